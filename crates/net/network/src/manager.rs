@@ -263,7 +263,7 @@ where
             event_sender.clone(),
         );
 
-        Ok(Self {
+        let network = Self {
             swarm,
             handle,
             from_handle_rx: UnboundedReceiverStream::new(from_handle_rx),
@@ -274,7 +274,11 @@ where
             num_active_peers,
             metrics: Default::default(),
             disconnect_metrics: Default::default(),
-        })
+        };
+        // trusted peers from the config are known but not connected yet
+        network.update_trusted_peer_metrics();
+
+        Ok(network)
     }
 
     /// Create a new [`NetworkManager`] instance and start a [`NetworkBuilder`] to configure all
@@ -555,15 +559,18 @@ where
                 .send_message(&peer_id, PeerMessage::PooledTransactions(msg)),
             NetworkHandleMessage::AddTrustedPeerId(peer_id) => {
                 self.swarm.state_mut().add_trusted_peer_id(peer_id);
+                self.update_trusted_peer_metrics();
             }
             NetworkHandleMessage::AddPeerAddress(peer, kind, addr) => {
                 // only add peer if we are not shutting down
                 if !self.swarm.is_shutting_down() {
                     self.swarm.state_mut().add_peer_kind(peer, kind, addr);
+                    self.update_trusted_peer_metrics();
                 }
             }
             NetworkHandleMessage::RemovePeer(peer_id, kind) => {
                 self.swarm.state_mut().remove_peer(peer_id, kind);
+                self.update_trusted_peer_metrics();
             }
             NetworkHandleMessage::DisconnectPeer(peer_id, reason) => {
                 self.swarm.sessions_mut().disconnect(peer_id, reason);
@@ -704,11 +711,13 @@ where
                 trace!(target: "net", ?peer_id, "Peer added");
                 self.event_sender.notify(NetworkEvent::PeerAdded(peer_id));
                 self.metrics.tracked_peers.set(self.swarm.state().peers().num_known_peers() as f64);
+                self.update_trusted_peer_metrics();
             }
             SwarmEvent::PeerRemoved(peer_id) => {
                 trace!(target: "net", ?peer_id, "Peer dropped");
                 self.event_sender.notify(NetworkEvent::PeerRemoved(peer_id));
                 self.metrics.tracked_peers.set(self.swarm.state().peers().num_known_peers() as f64);
+                self.update_trusted_peer_metrics();
             }
             SwarmEvent::SessionClosed { peer_id, remote_addr, error } => {
                 let total_active = self.num_active_peers.fetch_sub(1, Ordering::Relaxed) - 1;
@@ -875,6 +884,7 @@ where
         self.metrics
             .outgoing_connections
             .set(self.swarm.state().peers().num_outbound_connections() as f64);
+        self.update_trusted_peer_metrics();
     }
 
     /// Updates the metrics for pending connections
@@ -886,6 +896,15 @@ where
         self.metrics
             .total_pending_connections
             .set(self.swarm.sessions().num_pending_connections() as f64);
+        self.update_trusted_peer_metrics();
+    }
+
+    /// Updates the metrics for trusted peers
+    #[inline]
+    fn update_trusted_peer_metrics(&self) {
+        self.metrics
+            .disconnected_trusted_peers
+            .set(self.swarm.state().peers().num_disconnected_trusted_peers() as f64);
     }
 }
 
@@ -22,6 +22,9 @@ pub struct NetworkMetrics {
     /// Number of peers known to the node
     pub(crate) tracked_peers: Gauge,
 
+    /// Number of trusted peers that are currently not connected
+    pub(crate) disconnected_trusted_peers: Gauge,
+
     /// Cumulative number of failures of pending sessions
     pub(crate) pending_session_failures: Counter,
 
@@ -133,7 +133,7 @@ impl PeersManager {
     pub fn new(config: PeersConfig) -> Self {
         let PeersConfig {
             refill_slots_interval,
-            connection_info,
+            mut connection_info,
             reputation_weights,
             ban_list,
             ban_duration,
@@ -161,6 +161,10 @@ impl PeersManager {
             peers.entry(id).or_insert_with(|| Peer::new(SocketAddr::from((address, tcp_port))));
         }
 
+        // all peers are unconnected at this point
+        connection_info.num_disconnected_trusted =
+            peers.values().filter(|peer| peer.is_trusted()).count();
+
         Self {
             peers,
             trusted_peer_ids,
@@ -227,6 +231,12 @@ impl PeersManager {
         self.backed_off_peers.len()
     }
 
+    /// Returns the number of trusted peers that are currently not connected.
+    #[inline]
+    pub(crate) fn num_disconnected_trusted_peers(&self) -> usize {
+        self.connection_info.num_disconnected_trusted
+    }
+
     /// Invoked when a new _incoming_ tcp connection is accepted.
     ///
     /// returns an error if the inbound ip address is on the ban list
@@ -321,6 +331,7 @@ impl PeersManager {
                     self.connection_info.decr_state(peer.state);
                 }
 
+                self.connection_info.on_peer_state_change(peer, PeerConnectionState::In);
                 peer.state = PeerConnectionState::In;
 
                 is_trusted = is_trusted || peer.is_trusted();
@@ -472,6 +483,7 @@ impl PeersManager {
     pub(crate) fn on_outgoing_pending_session_gracefully_closed(&mut self, peer_id: &PeerId) {
         if let Some(peer) = self.peers.get_mut(peer_id) {
             self.connection_info.decr_state(peer.state);
+            self.connection_info.on_peer_state_change(peer, PeerConnectionState::Idle);
             peer.state = PeerConnectionState::Idle;
         }
     }
@@ -502,6 +514,8 @@ impl PeersManager {
                     // we reset the backoff counter since we're able to establish a successful
                     // session to that peer
                     entry.get_mut().severe_backoff_counter = 0;
+                    self.connection_info
+                        .on_peer_state_change(entry.get(), PeerConnectionState::Idle);
                     entry.get_mut().state = PeerConnectionState::Idle;
                     return
                 }
@@ -517,7 +531,13 @@ impl PeersManager {
         if let Some(peer) = self.peers.get_mut(&peer_id) {
             self.connection_info.decr_state(peer.state);
             self.connection_info.inc_out();
+            self.connection_info.on_peer_state_change(peer, PeerConnectionState::Out);
             peer.state = PeerConnectionState::Out;
+            if peer.is_trusted() {
+                // we're able to establish a session to the trusted peer, so its next backoff
+                // starts from the low duration again
+                peer.severe_backoff_counter = 0;
+            }
         }
     }
 
@@ -570,6 +590,7 @@ impl PeersManager {
             // issues.
             if let Some((peer_id, peer)) = self.peers.remove_entry(peer_id) {
                 self.connection_info.decr_state(peer.state);
+                self.connection_info.on_peer_removed(&peer);
                 self.queued_actions.push_back(PeerAction::PeerRemoved(peer_id));
             }
 
@@ -589,13 +610,18 @@ impl PeersManager {
 
             if let Some(peer) = self.peers.get_mut(peer_id) {
                 if let Some(kind) = err.should_backoff() {
-                    // Increment peer.backoff_counter
-                    if kind.is_severe() {
+                    // Increment peer.backoff_counter, trusted peers count every backoff
+                    if kind.is_severe() || peer.is_trusted() {
                         peer.severe_backoff_counter = peer.severe_backoff_counter.saturating_add(1);
                     }
 
-                    let backoff_time =
-                        self.backoff_durations.backoff_until(kind, peer.severe_backoff_counter);
+                    let backoff_time = if peer.is_trusted() {
+                        // trusted peers are never removed, so we retry them quickly and back off
+                        // exponentially instead
+                        self.backoff_durations.trusted_backoff_until(peer.severe_backoff_counter)
+                    } else {
+                        self.backoff_durations.backoff_until(kind, peer.severe_backoff_counter)
+                    };
 
                     // The peer has signaled that it is currently unable to process any more
                     // connections, so we will hold off on attempting any new connections for a
@@ -608,6 +634,7 @@ impl PeersManager {
                 };
 
                 self.connection_info.decr_state(peer.state);
+                self.connection_info.on_peer_state_change(peer, PeerConnectionState::Idle);
                 peer.state = PeerConnectionState::Idle;
 
                 if peer.severe_backoff_counter > self.max_backoff_count && !peer.is_trusted() {
@@ -696,6 +723,7 @@ impl PeersManager {
         match self.peers.entry(peer_id) {
             Entry::Occupied(mut entry) => {
                 let peer = entry.get_mut();
+                self.connection_info.on_peer_kind_change(peer, kind);
                 peer.kind = kind;
                 peer.fork_id = fork_id;
                 peer.addr = addr;
@@ -711,6 +739,7 @@ impl PeersManager {
                 trace!(target: "net::peers", ?peer_id, ?addr, "discovered new node");
                 let mut peer = Peer::with_kind(addr, kind);
                 peer.fork_id = fork_id;
+                self.connection_info.on_peer_added(&peer);
                 entry.insert(peer);
                 self.queued_actions.push_back(PeerAction::PeerAdded(peer_id));
             }
@@ -756,6 +785,7 @@ impl PeersManager {
         }
 
         let peer = entry.get_mut();
+        self.connection_info.on_peer_kind_change(peer, PeerKind::Basic);
         peer.kind = PeerKind::Basic;
 
         self.trusted_peer_ids.remove(&peer_id);
@@ -814,7 +844,7 @@ impl PeersManager {
 
         // as long as there a slots available fill them with the best peers
         while self.connection_info.has_out_capacity() {
-            let action = {
+            let (action, is_trusted) = {
                 let (peer_id, peer) = match self.best_unconnected() {
                     Some(peer) => peer,
                     _ => break,
@@ -823,10 +853,13 @@ impl PeersManager {
                 trace!(target: "net::peers", ?peer_id, addr=?peer.addr, "schedule outbound connection");
 
                 peer.state = PeerConnectionState::PendingOut;
-                PeerAction::Connect { peer_id, remote_addr: peer.addr }
+                (PeerAction::Connect { peer_id, remote_addr: peer.addr }, peer.is_trusted())
             };
 
             self.connection_info.inc_pending_out();
+            if is_trusted {
+                self.connection_info.decr_disconnected_trusted();
+            }
 
             self.queued_actions.push_back(action);
         }
@@ -935,6 +968,9 @@ pub struct ConnectionInfo {
     /// Counter for pending inbound connections.
     #[cfg_attr(feature = "serde", serde(skip))]
     num_pending_in: usize,
+    /// Counter for trusted peers that are currently not connected.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    num_disconnected_trusted: usize,
     /// Maximum allowed outbound connections.
     max_outbound: usize,
     /// Maximum allowed inbound connections.
@@ -998,6 +1034,53 @@ impl ConnectionInfo {
     fn decr_pending_in(&mut self) {
         self.num_pending_in -= 1;
     }
+
+    fn inc_disconnected_trusted(&mut self) {
+        self.num_disconnected_trusted += 1;
+    }
+
+    fn decr_disconnected_trusted(&mut self) {
+        self.num_disconnected_trusted -= 1;
+    }
+
+    /// Tracks a trusted peer moving between connected and unconnected, must be called before the
+    /// peer's state is set to `state`.
+    fn on_peer_state_change(&mut self, peer: &Peer, state: PeerConnectionState) {
+        if !peer.is_trusted() {
+            return
+        }
+        match (peer.state.is_unconnected(), state.is_unconnected()) {
+            (true, false) => self.decr_disconnected_trusted(),
+            (false, true) => self.inc_disconnected_trusted(),
+            _ => {}
+        }
+    }
+
+    /// Tracks a peer changing its kind, must be called before the peer's kind is set to `kind`.
+    fn on_peer_kind_change(&mut self, peer: &Peer, kind: PeerKind) {
+        if !peer.state.is_unconnected() {
+            return
+        }
+        match (peer.is_trusted(), kind.is_trusted()) {
+            (false, true) => self.inc_disconnected_trusted(),
+            (true, false) => self.decr_disconnected_trusted(),
+            _ => {}
+        }
+    }
+
+    /// Tracks a peer that was added to the peer set.
+    fn on_peer_added(&mut self, peer: &Peer) {
+        if peer.is_trusted() && peer.state.is_unconnected() {
+            self.inc_disconnected_trusted();
+        }
+    }
+
+    /// Tracks a peer that was removed from the peer set.
+    fn on_peer_removed(&mut self, peer: &Peer) {
+        if peer.is_trusted() && peer.state.is_unconnected() {
+            self.decr_disconnected_trusted();
+        }
+    }
 }
 
 impl Default for ConnectionInfo {
@@ -1010,6 +1093,7 @@ impl Default for ConnectionInfo {
             max_concurrent_outbound_dials: DEFAULT_MAX_COUNT_CONCURRENT_OUTBOUND_DIALS,
             num_pending_out: 0,
             num_pending_in: 0,
+            num_disconnected_trusted: 0,
         }
     }
 }
@@ -1032,6 +1116,8 @@ pub struct Peer {
     /// Whether the peer is currently backed off.
     backed_off: bool,
     /// Counts number of times the peer was backed off due to a severe [BackoffKind].
+    ///
+    /// For trusted peers, every backoff is counted.
     severe_backoff_counter: u8,
 }
 
@@ -1289,7 +1375,8 @@ pub struct PeersConfig {
     /// With a backoff counter of 5 and a backoff duration of 1h, the minimum time spent of the
     /// peer in the table is the sum of all backoffs (1h + 2h + 3h + 4h + 5h = 15h).
     ///
-    /// Note: this does not apply to trusted peers.
+    /// Note: this does not apply to trusted peers, which are backed off exponentially from the low
+    /// backoff duration up to the maximum backoff duration instead.
     pub max_backoff_count: u8,
     /// Basic nodes to connect to.
     #[cfg_attr(feature = "serde", serde(skip))]
@@ -1498,6 +1585,19 @@ impl PeerBackoffDurations {
         now + backoff_time.min(self.max)
     }
 
+    /// Returns the timestamp until which we should backoff a trusted peer.
+    ///
+    /// Trusted peers should be reconnected to quickly, so regardless of the [`BackoffKind`] the
+    /// backoff starts at the low duration and doubles with each backoff attempt: `low *
+    /// 2^(backoff_counter - 1)`. The Backoff duration is capped by the configured maximum backoff
+    /// duration.
+    pub fn trusted_backoff_until(&self, backoff_counter: u8) -> std::time::Instant {
+        let exponent = backoff_counter.saturating_sub(1) as u32;
+        let backoff_time = self.low.saturating_mul(2u32.saturating_pow(exponent));
+        let now = std::time::Instant::now();
+        now + backoff_time.min(self.max)
+    }
+
     /// Returns durations for testing.
     #[cfg(test)]
     const fn test() -> Self {
@@ -1832,6 +1932,113 @@ mod tests {
         assert!(backoff_time.duration_since(now) > backoff_duration);
     }
 
+    #[tokio::test]
+    async fn test_trusted_backoff_calculations() {
+        let backoff_durations = PeerBackoffDurations::default();
+        let now = std::time::Instant::now();
+
+        // First backoff of a trusted peer is the low backoff duration, 30 seconds
+        let backoff_time = backoff_durations.trusted_backoff_until(1);
+        assert!(backoff_time.duration_since(now) >= backoff_durations.low);
+        assert!(backoff_time <= std::time::Instant::now() + backoff_durations.low);
+
+        // Duration of the backoff should be 2^2 * 30 seconds = 2 minutes
+        let backoff_time = backoff_durations.trusted_backoff_until(3);
+        assert!(backoff_time.duration_since(now) >= Duration::from_secs(2 * 60));
+        assert!(backoff_time <= std::time::Instant::now() + Duration::from_secs(2 * 60));
+
+        // Backoff is capped by the maximum backoff duration
+        let backoff_time = backoff_durations.trusted_backoff_until(u8::MAX);
+        assert!(backoff_time.duration_since(now) >= backoff_durations.max);
+        assert!(backoff_time <= std::time::Instant::now() + backoff_durations.max);
+    }
+
+    #[tokio::test]
+    async fn test_trusted_backoff_on_connection_refused() {
+        let trusted_peer = PeerId::random();
+        let trusted_sock = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let backoff_durations = PeerBackoffDurations::default();
+        let config = PeersConfig::test()
+            .with_backoff_durations(backoff_durations)
+            .with_trusted_nodes(HashSet::from([NodeRecord {
+                address: IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)),
+                tcp_port: 8008,
+                udp_port: 8008,
+                id: trusted_peer,
+            }]));
+        let mut peers = PeersManager::new(config);
+
+        match event!(peers) {
+            PeerAction::Connect { peer_id, .. } => {
+                assert_eq!(peer_id, trusted_peer);
+            }
+            _ => unreachable!(),
+        }
+
+        // First refused dial backs off for the low duration instead of the high one
+        let now = std::time::Instant::now();
+        peers.on_outgoing_connection_failure(
+            &trusted_sock,
+            &trusted_peer,
+            &io::Error::new(io::ErrorKind::ConnectionRefused, "peer unreachable"),
+        );
+        let backoff_until = *peers.backed_off_peers.get(&trusted_peer).unwrap();
+        assert!(backoff_until.duration_since(now) >= backoff_durations.low);
+        assert!(backoff_until <= std::time::Instant::now() + backoff_durations.low);
+        assert!(peers.peers.contains_key(&trusted_peer));
+
+        // Second refused dial doubles the backoff
+        let now = std::time::Instant::now();
+        peers.on_outgoing_connection_failure(
+            &trusted_sock,
+            &trusted_peer,
+            &io::Error::new(io::ErrorKind::ConnectionRefused, "peer unreachable"),
+        );
+        let backoff_until = *peers.backed_off_peers.get(&trusted_peer).unwrap();
+        assert!(backoff_until.duration_since(now) >= backoff_durations.low * 2);
+        assert!(backoff_until <= std::time::Instant::now() + backoff_durations.low * 2);
+    }
+
+    #[tokio::test]
+    async fn test_disconnected_trusted_peers() {
+        let trusted_peer = PeerId::random();
+        let trusted_sock = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let config = PeersConfig::test().with_trusted_nodes(HashSet::from([NodeRecord {
+            address: IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)),
+            tcp_port: 8008,
+            udp_port: 8008,
+            id: trusted_peer,
+        }]));
+        let mut peers = PeersManager::new(config);
+        assert_eq!(peers.num_disconnected_trusted_peers(), 1);
+
+        match event!(peers) {
+            PeerAction::Connect { peer_id, remote_addr } => {
+                assert_eq!(peer_id, trusted_peer);
+                assert_eq!(remote_addr, trusted_sock);
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(peers.num_disconnected_trusted_peers(), 0);
+
+        peers.on_outgoing_connection_failure(
+            &trusted_sock,
+            &trusted_peer,
+            &io::Error::new(io::ErrorKind::ConnectionRefused, "peer unreachable"),
+        );
+        assert_eq!(peers.num_disconnected_trusted_peers(), 1);
+
+        // Newly added trusted peers are counted, and untrusting a peer removes it from the count
+        let basic_peer = PeerId::random();
+        let basic_sock = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8009);
+        peers.add_peer(basic_peer, basic_sock, None);
+        assert_eq!(peers.num_disconnected_trusted_peers(), 1);
+        peers.add_trusted_peer(basic_peer, basic_sock);
+        assert_eq!(peers.num_disconnected_trusted_peers(), 2);
+        peers.remove_peer_from_trusted_set(basic_peer);
+        assert_eq!(peers.num_disconnected_trusted_peers(), 1);
+    }
+
     #[tokio::test]
     async fn test_ban_on_active_drop() {
         let peer = PeerId::random();
@@ -16,6 +16,9 @@ pub struct DatabaseArgs {
     /// NFS volume.
     #[arg(long = "db.exclusive")]
     pub exclusive: Option<bool>,
+    /// Open the database even if it was last written by a client with a newer major version.
+    #[arg(long = "db.force-client-version")]
+    pub force_client_version: bool,
 }
 
 impl DatabaseArgs {
@@ -24,6 +27,7 @@ impl DatabaseArgs {
         reth_db::mdbx::DatabaseArguments::new(default_client_version())
             .with_log_level(self.log_level)
             .with_exclusive(self.exclusive)
+            .with_skip_client_version_check(self.force_client_version)
     }
 }
 
@@ -87,6 +87,9 @@ pub struct DatabaseArguments {
     ///
     /// This flag affects only at environment opening but can't be changed after.
     exclusive: Option<bool>,
+    /// Skip the check that refuses to open a database last written by a client with a newer
+    /// major version.
+    skip_client_version_check: bool,
 }
 
 impl DatabaseArguments {
@@ -97,6 +100,7 @@ impl DatabaseArguments {
             log_level: None,
             max_read_transaction_duration: None,
             exclusive: None,
+            skip_client_version_check: false,
         }
     }
 
@@ -121,10 +125,21 @@ impl DatabaseArguments {
         self
     }
 
+    /// Set whether to skip the client version compatibility check.
+    pub fn with_skip_client_version_check(mut self, skip_client_version_check: bool) -> Self {
+        self.skip_client_version_check = skip_client_version_check;
+        self
+    }
+
     /// Returns the client version if any.
     pub fn client_version(&self) -> &ClientVersion {
         &self.client_version
     }
+
+    /// Returns `true` if the client version compatibility check should be skipped.
+    pub fn skip_client_version_check(&self) -> bool {
+        self.skip_client_version_check
+    }
 }
 
 /// Wrapper for the libmdbx environment: [Environment]
@@ -412,6 +427,35 @@ impl DatabaseEnv {
         Ok(())
     }
 
+    /// Returns the client version with the highest major version that accessed the database with
+    /// write privileges, if any. If several versions share the highest major version, the most
+    /// recent one is returned.
+    ///
+    /// Versions without a parseable major version are ignored. Returns [None] if the version
+    /// history table doesn't exist yet.
+    pub fn highest_major_client_version(&self) -> Result<Option<ClientVersion>, DatabaseError> {
+        let tx = self.tx()?;
+        let mut version_cursor = match tx.cursor_read::<tables::VersionHistory>() {
+            Ok(cursor) => cursor,
+            Err(DatabaseError::Open(err))
+                if err.code == reth_libmdbx::Error::NotFound.to_err_code() =>
+            {
+                return Ok(None)
+            }
+            Err(err) => return Err(err),
+        };
+
+        let mut highest: Option<(u64, ClientVersion)> = None;
+        for entry in version_cursor.walk_range(..)? {
+            let (_, version) = entry?;
+            let Some(major) = version.major_version() else { continue };
+            if !highest.as_ref().is_some_and(|(highest_major, _)| major < *highest_major) {
+                highest = Some((major, version));
+            }
+        }
+        Ok(highest.map(|(_, version)| version))
+    }
+
     /// Records version that accesses the database with write privileges.
     pub fn record_client_version(&self, version: ClientVersion) -> Result<(), DatabaseError> {
         if version.is_empty() {
@@ -126,7 +126,11 @@ pub fn init_db<P: AsRef<Path>>(path: P, args: DatabaseArguments) -> eyre::Result
     #[cfg(feature = "mdbx")]
     {
         let client_version = args.client_version().clone();
+        let skip_client_version_check = args.skip_client_version_check();
         let db = create_db(path, args)?;
+        if !skip_client_version_check {
+            check_client_version_history(&db, &client_version)?;
+        }
         db.create_tables()?;
         db.record_client_version(client_version)?;
         Ok(db)
@@ -157,6 +161,9 @@ pub fn open_db(path: &Path, args: DatabaseArguments) -> eyre::Result<DatabaseEnv
     {
         let db = DatabaseEnv::open(path, DatabaseEnvKind::RW, args.clone())
             .with_context(|| format!("Could not open database at path: {}", path.display()))?;
+        if !args.skip_client_version_check() {
+            check_client_version_history(&db, args.client_version())?;
+        }
         db.record_client_version(args.client_version().clone())?;
         Ok(db)
     }
@@ -166,6 +173,22 @@ pub fn open_db(path: &Path, args: DatabaseArguments) -> eyre::Result<DatabaseEnv
     }
 }
 
+/// Checks that the database was never written by a client with a newer major version than
+/// `client_version`.
+///
+/// The whole version history is considered, so that a single forced open by an older client
+/// doesn't lift the check for subsequent opens.
+#[cfg(feature = "mdbx")]
+fn check_client_version_history(
+    db: &DatabaseEnv,
+    client_version: &models::client_version::ClientVersion,
+) -> eyre::Result<()> {
+    if let Some(highest_version) = db.highest_major_client_version()? {
+        version::check_client_version(&highest_version, client_version)?;
+    }
+    Ok(())
+}
+
 /// Collection of database test utilities
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils {
@@ -312,9 +335,12 @@ mod tests {
         init_db,
         mdbx::DatabaseArguments,
         models::client_version::ClientVersion,
-        open_db, tables,
+        open_db, open_db_read_only,
+        table::Table,
+        tables,
         transaction::DbTx,
-        version::{db_version_file_path, DatabaseVersionError},
+        version::{db_version_file_path, ClientVersionError, DatabaseVersionError},
+        DatabaseError,
     };
     use assert_matches::assert_matches;
     use reth_libmdbx::MaxReadTransactionDuration;
@@ -444,4 +470,101 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn db_client_version_newer_major() {
+        let path = tempdir().unwrap();
+
+        let newer_version = ClientVersion { version: String::from("2.0.0"), ..Default::default() };
+        let older_version = ClientVersion { version: String::from("1.0.0"), ..Default::default() };
+        {
+            init_db(&path, DatabaseArguments::new(newer_version.clone())).unwrap();
+        }
+
+        // Database written by a newer major version is refused
+        {
+            let db = init_db(&path, DatabaseArguments::new(older_version.clone()));
+            assert_matches!(
+                db.unwrap_err().downcast_ref::<ClientVersionError>(),
+                Some(ClientVersionError::NewerMajorVersion { .. })
+            );
+
+            let db = open_db(path.path(), DatabaseArguments::new(older_version.clone()));
+            assert_matches!(
+                db.unwrap_err().downcast_ref::<ClientVersionError>(),
+                Some(ClientVersionError::NewerMajorVersion { .. })
+            );
+        }
+
+        // Check can be skipped, and the older version is appended to the history
+        std::thread::sleep(Duration::from_secs(1));
+        {
+            let db = init_db(
+                &path,
+                DatabaseArguments::new(older_version.clone()).with_skip_client_version_check(true),
+            )
+            .unwrap();
+            let tx = db.tx().unwrap();
+            let mut cursor = tx.cursor_read::<tables::VersionHistory>().unwrap();
+            assert_eq!(
+                cursor
+                    .walk_range(..)
+                    .unwrap()
+                    .map(|x| x.map(|(_, v)| v))
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap(),
+                vec![newer_version, older_version.clone()]
+            );
+        }
+
+        // Forced open doesn't lift the check for subsequent opens
+        {
+            let db = init_db(&path, DatabaseArguments::new(older_version.clone()));
+            assert_matches!(
+                db.unwrap_err().downcast_ref::<ClientVersionError>(),
+                Some(ClientVersionError::NewerMajorVersion { .. })
+            );
+
+            let db = open_db(path.path(), DatabaseArguments::new(older_version));
+            assert_matches!(
+                db.unwrap_err().downcast_ref::<ClientVersionError>(),
+                Some(ClientVersionError::NewerMajorVersion { .. })
+            );
+        }
+    }
+
+    #[test]
+    fn db_client_version_newer_major_untouched() {
+        let path = tempdir().unwrap();
+
+        let newer_version = ClientVersion { version: String::from("2.0.0"), ..Default::default() };
+        let older_version = ClientVersion { version: String::from("1.0.0"), ..Default::default() };
+
+        // Newer version drops a table
+        {
+            let db = init_db(&path, DatabaseArguments::new(newer_version)).unwrap();
+            let tx = db.tx_mut().unwrap();
+            let table = tx.inner.open_db(Some(tables::CanonicalHeaders::NAME)).unwrap();
+            unsafe { tx.inner.drop_db(table).unwrap() };
+            tx.commit().unwrap();
+        }
+
+        // Older version is refused
+        {
+            let db = init_db(&path, DatabaseArguments::new(older_version));
+            assert_matches!(
+                db.unwrap_err().downcast_ref::<ClientVersionError>(),
+                Some(ClientVersionError::NewerMajorVersion { .. })
+            );
+        }
+
+        // Dropped table is not recreated by the refused client
+        {
+            let db =
+                open_db_read_only(path.path(), DatabaseArguments::new(ClientVersion::default()))
+                    .unwrap();
+            let tx = db.tx().unwrap();
+            assert_matches!(tx.get::<tables::CanonicalHeaders>(0), Err(DatabaseError::Open(_)));
+        }
+    }
 }
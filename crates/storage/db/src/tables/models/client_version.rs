@@ -20,6 +20,14 @@ impl ClientVersion {
     pub fn is_empty(&self) -> bool {
         self.version.is_empty() && self.git_sha.is_empty() && self.build_timestamp.is_empty()
     }
+
+    /// Returns the major component of the client version, if it can be parsed.
+    ///
+    /// A leading `v` is ignored, so both `0.2.0-beta.7` and `v1` are accepted.
+    pub fn major_version(&self) -> Option<u64> {
+        let version = self.version.strip_prefix('v').unwrap_or(&self.version);
+        version.split(['.', '-', '+']).next()?.parse().ok()
+    }
 }
 
 impl Compact for ClientVersion {
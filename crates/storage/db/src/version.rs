@@ -1,5 +1,6 @@
 //! Database version utils.
 
+use crate::models::client_version::ClientVersion;
 use std::{
     fs, io,
     path::{Path, PathBuf},
@@ -41,6 +42,44 @@ pub enum DatabaseVersionError {
     },
 }
 
+/// Error when checking a client version against the database version history using
+/// [check_client_version].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ClientVersionError {
+    /// The database was written by a client with a newer major version.
+    #[error(
+        "database was written by client {last}, which is a newer major version than the current \
+         client {current}; pass --db.force-client-version to override"
+    )]
+    NewerMajorVersion {
+        /// The version of the newest major client that wrote to the database.
+        last: String,
+        /// The version of the current client.
+        current: String,
+    },
+}
+
+/// Checks that the current client version is compatible with a client version that wrote to the
+/// database.
+///
+/// Returns [Ok] if the major version of `current` is not lower than the major version of `last`,
+/// or if either of them can't be parsed.
+/// Otherwise, returns [ClientVersionError::NewerMajorVersion].
+pub fn check_client_version(
+    last: &ClientVersion,
+    current: &ClientVersion,
+) -> Result<(), ClientVersionError> {
+    match (last.major_version(), current.major_version()) {
+        (Some(last_major), Some(current_major)) if last_major > current_major => {
+            Err(ClientVersionError::NewerMajorVersion {
+                last: last.version.clone(),
+                current: current.version.clone(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Checks the database version file with [DB_VERSION_FILE_NAME] name.
 ///
 /// Returns [Ok] if file is found and has one line which equals to [DB_VERSION].
@@ -84,7 +123,11 @@ pub fn db_version_file_path<P: AsRef<Path>>(db_path: P) -> PathBuf {
 
 #[cfg(test)]
 mod tests {
-    use super::{check_db_version_file, db_version_file_path, DatabaseVersionError};
+    use super::{
+        check_client_version, check_db_version_file, db_version_file_path, ClientVersionError,
+        DatabaseVersionError,
+    };
+    use crate::models::client_version::ClientVersion;
     use assert_matches::assert_matches;
     use std::fs;
     use tempfile::tempdir;
@@ -114,4 +157,22 @@ mod tests {
         let result = check_db_version_file(&dir);
         assert_matches!(result, Err(DatabaseVersionError::VersionMismatch { version: 0 }));
     }
+
+    #[test]
+    fn client_version_compatibility() {
+        let version = |v: &str| ClientVersion { version: v.to_string(), ..Default::default() };
+
+        assert_eq!(check_client_version(&version("0.2.0-beta.7"), &version("0.2.0")), Ok(()));
+        assert_eq!(check_client_version(&version("v1"), &version("2.0.0")), Ok(()));
+        assert_eq!(check_client_version(&version("1.1.0"), &version("1.0.0")), Ok(()));
+        assert_eq!(check_client_version(&version("invalid"), &version("1.0.0")), Ok(()));
+        assert_eq!(check_client_version(&version("2.0.0"), &version("")), Ok(()));
+        assert_eq!(
+            check_client_version(&version("2.0.0"), &version("v1.9.9")),
+            Err(ClientVersionError::NewerMajorVersion {
+                last: String::from("2.0.0"),
+                current: String::from("v1.9.9")
+            })
+        );
+    }
 }